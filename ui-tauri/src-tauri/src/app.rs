@@ -0,0 +1,116 @@
+// AppBuilder: wires the embedded backend into the Tauri app lifecycle.
+
+use tauri::{App, Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::backend::Event;
+use crate::server::ServerHandle;
+use crate::Notifications;
+
+type SetupHook = Box<dyn FnOnce(&mut App) + Send>;
+
+/// Thin builder around [`tauri::Builder`] that boots the in-process HTTP + WS
+/// servers during `setup` and stashes their [`ServerHandle`] in managed state,
+/// so the backend launches with the app and shuts down when it exits.
+#[derive(Default)]
+pub struct AppBuilder {
+    setup: Option<SetupHook>,
+}
+
+impl AppBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a closure run once the app and embedded servers are up.
+    #[must_use]
+    pub fn setup<F>(mut self, setup: F) -> Self
+    where
+        F: FnOnce(&mut App) + Send + 'static,
+    {
+        self.setup.replace(Box::new(setup));
+        self
+    }
+
+    /// Build and run the Tauri app, booting the backend before handing control
+    /// to the user-supplied setup hook.
+    pub fn run(self) {
+        let user_setup = self.setup;
+        let builder = tauri::Builder::default()
+            .plugin(tauri_plugin_opener::init())
+            .plugin(tauri_plugin_notification::init());
+
+        // On mobile the backend URL is a custom IPC scheme (`http://p2pchat.localhost`)
+        // rather than a loopback port. The handler answers every request with an
+        // empty 200 response; requests are not passed on to the backend.
+        #[cfg(mobile)]
+        let builder = builder.register_uri_scheme_protocol(crate::mobile::SCHEME, |_ctx, _req| {
+            tauri::http::Response::builder()
+                .status(200)
+                .body(Vec::new())
+                .unwrap()
+        });
+
+        builder
+            .setup(move |app| {
+                app.manage(Notifications::default());
+                let (mut handle, urls) = ServerHandle::start()?;
+                if let Some(events) = handle.events.take() {
+                    bridge_events(app.handle().clone(), events);
+                }
+                app.manage(urls);
+                // Dropped on app exit, which signals the servers to shut down.
+                app.manage(handle);
+                if let Some(setup) = user_setup {
+                    setup(app);
+                }
+                Ok(())
+            })
+            .invoke_handler(tauri::generate_handler![
+                crate::get_backend_url,
+                crate::get_ws_url,
+                crate::notify_message,
+                crate::set_notifications_enabled
+            ])
+            .run(tauri::generate_context!())
+            .expect("error while running tauri application");
+    }
+}
+
+/// Drain the P2P event stream and re-emit each event to the frontend so the UI
+/// can `listen('peer-message', ...)` / `listen('peer-status', ...)` instead of
+/// managing its own WebSocket. Events flow even before the UI has connected.
+fn bridge_events(app: tauri::AppHandle, mut events: crate::backend::Events) {
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = events.recv().await {
+            let _ = match event {
+                Event::Message(msg) => {
+                    notify_if_unfocused(&app, &msg);
+                    app.emit("peer-message", msg)
+                }
+                Event::Status(status) => app.emit("peer-status", status),
+            };
+        }
+    });
+}
+
+/// Raise a desktop/mobile notification for an incoming message when the user is
+/// not looking — i.e. the main window is unfocused — and notifications are on.
+fn notify_if_unfocused(app: &tauri::AppHandle, msg: &crate::backend::PeerMessage) {
+    if !app.state::<Notifications>().enabled() {
+        return;
+    }
+    let focused = app
+        .get_webview_window("main")
+        .and_then(|w| w.is_focused().ok())
+        .unwrap_or(false);
+    if focused {
+        return;
+    }
+    let _ = app
+        .notification()
+        .builder()
+        .title(&msg.peer_id)
+        .body(&msg.body)
+        .show();
+}
@@ -0,0 +1,37 @@
+// P2P backend event stream consumed by the Tauri event bridge.
+
+use serde::Serialize;
+use tokio::sync::mpsc;
+
+/// An incoming chat message from a remote peer.
+#[derive(Clone, Serialize)]
+pub struct PeerMessage {
+    pub peer_id: String,
+    pub timestamp: i64,
+    pub body: String,
+}
+
+/// A presence change for a remote peer (online / offline).
+#[derive(Clone, Serialize)]
+pub struct PeerStatus {
+    pub peer_id: String,
+    pub timestamp: i64,
+    pub online: bool,
+}
+
+/// Events surfaced by the P2P layer and forwarded to the frontend.
+pub enum Event {
+    Message(PeerMessage),
+    Status(PeerStatus),
+}
+
+/// Receiving half of the backend event stream.
+pub type Events = mpsc::UnboundedReceiver<Event>;
+
+/// Create the channel the P2P layer publishes its events on.
+///
+/// This does not start any networking: the sending half is handed to the
+/// server task, and callers drain the receiver and fan the events out to the UI.
+pub fn subscribe() -> (mpsc::UnboundedSender<Event>, Events) {
+    mpsc::unbounded_channel()
+}
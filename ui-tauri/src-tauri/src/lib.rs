@@ -1,20 +1,71 @@
 // Tauri backend commands and plugin setup
 
+mod app;
+mod backend;
+#[cfg(mobile)]
+mod mobile;
+mod server;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tauri::State;
+use tauri_plugin_notification::NotificationExt;
+
+use crate::app::AppBuilder;
+
+/// Whether new-message notifications are shown, toggled from the UI.
+pub(crate) struct Notifications(pub AtomicBool);
+
+impl Default for Notifications {
+    fn default() -> Self {
+        Notifications(AtomicBool::new(true))
+    }
+}
+
+impl Notifications {
+    pub(crate) fn enabled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Resolved backend/WebSocket endpoints, shared as Tauri managed state so every
+/// command reads from a single source of truth. They are produced by the server
+/// itself once it has bound (see [`server::ServerHandle::start`]), so the URLs
+/// always reflect the ports actually in use.
+pub(crate) struct Urls {
+    pub(crate) backend: String,
+    pub(crate) ws: String,
+}
+
+#[tauri::command]
+fn get_backend_url(urls: State<'_, Urls>) -> String {
+    urls.backend.clone()
+}
+
+#[tauri::command]
+fn get_ws_url(urls: State<'_, Urls>) -> String {
+    urls.ws.clone()
+}
+
+/// Raise a native OS notification. Exposed so the frontend can surface activity
+/// directly in addition to the automatic new-message notifications.
 #[tauri::command]
-fn get_backend_url() -> String {
-    "http://127.0.0.1:8080".to_string()
+fn notify_message(app: tauri::AppHandle, title: String, body: String) -> Result<(), String> {
+    app.notification()
+        .builder()
+        .title(title)
+        .body(body)
+        .show()
+        .map_err(|e| e.to_string())
 }
 
+/// Mute or unmute automatic new-message notifications.
 #[tauri::command]
-fn get_ws_url() -> String {
-    "ws://127.0.0.1:8081/events".to_string()
+fn set_notifications_enabled(state: State<'_, Notifications>, enabled: bool) {
+    state.0.store(enabled, Ordering::Relaxed);
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
-        .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![get_backend_url, get_ws_url])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+    AppBuilder::new().run();
 }
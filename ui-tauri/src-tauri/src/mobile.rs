@@ -0,0 +1,30 @@
+// Mobile (Android/iOS) networking endpoints for the embedded backend.
+//
+// On mobile the backend runs in-process, so a `127.0.0.1` TCP loopback port is
+// meaningless: there is no separate process to reach. Instead the backend URL
+// is a Tauri custom URI scheme registered on the builder (see `AppBuilder::run`),
+// whose handler answers every request with an empty 200 response, and
+// incoming P2P activity is delivered to the UI through the Tauri event bridge
+// (`peer-message` / `peer-status`) rather than a separate WebSocket.
+//
+// Build wiring: the `gen/android` and `gen/apple` projects are produced by
+// `tauri android init` / `tauri ios init` (they are generated artifacts, not
+// checked in). `run()` carries `#[cfg_attr(mobile, tauri::mobile_entry_point)]`,
+// so the generated entry point picks it up and `tauri android dev` launches
+// this client against the in-process backend.
+
+/// Custom URI scheme the in-process backend is served on for mobile webviews.
+pub const SCHEME: &str = "p2pchat";
+
+/// HTTP origin reported as the backend URL on mobile. Requests to it are
+/// answered by the custom scheme handler, not by the backend.
+pub fn backend_url() -> String {
+    format!("http://{SCHEME}.localhost")
+}
+
+/// Endpoint reported for the event channel. Mobile has no standalone WebSocket:
+/// events arrive over the Tauri event bridge, so this points at the same IPC
+/// origin and the frontend listens via `listen('peer-message', ...)`.
+pub fn ws_url() -> String {
+    backend_url()
+}
@@ -0,0 +1,132 @@
+// Embedded HTTP + WebSocket P2P backend, booted inside the Tauri process.
+
+use std::io;
+
+use tokio::runtime::Runtime;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::backend::{self, Event};
+use crate::Urls;
+
+/// Handle to the in-process backend servers.
+///
+/// Dropping it signals the HTTP and WS tasks to stop and shuts the runtime
+/// down, so the servers die with the Tauri app and never orphan a process.
+pub struct ServerHandle {
+    shutdown: Option<oneshot::Sender<()>>,
+    runtime: Option<Runtime>,
+    /// Event stream the networking tasks publish incoming P2P activity on.
+    pub events: Option<backend::Events>,
+}
+
+#[cfg(not(mobile))]
+impl ServerHandle {
+    /// Bind the HTTP + WS listeners and boot the servers on a background
+    /// runtime, returning the URLs that reflect the ports actually bound.
+    ///
+    /// A pinned `P2PCHAT_BACKEND_URL` / `P2PCHAT_WS_URL` points the UI at a
+    /// remote relay and skips the local bind; otherwise we take ephemeral
+    /// loopback ports so two instances can coexist on one machine. The listener
+    /// is held open for the server's lifetime, so the advertised port is always
+    /// the one in use — no probe-then-release gap for another process to steal.
+    pub fn start() -> io::Result<(Self, Urls)> {
+        use std::net::TcpListener;
+
+        let runtime = Runtime::new()?;
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let (tx, events) = backend::subscribe();
+
+        let backend_pin = std::env::var("P2PCHAT_BACKEND_URL").ok();
+        let ws_pin = std::env::var("P2PCHAT_WS_URL").ok();
+
+        let http = match backend_pin {
+            Some(_) => None,
+            None => Some(TcpListener::bind("127.0.0.1:0")?),
+        };
+        let ws_listener = match ws_pin {
+            Some(_) => None,
+            None => Some(TcpListener::bind("127.0.0.1:0")?),
+        };
+
+        let backend = match backend_pin {
+            Some(url) => url,
+            None => format!("http://{}", http.as_ref().unwrap().local_addr()?),
+        };
+        let ws = match ws_pin {
+            Some(url) => url,
+            None => format!("ws://{}/events", ws_listener.as_ref().unwrap().local_addr()?),
+        };
+
+        runtime.spawn(async move { serve(http, ws_listener, tx, shutdown_rx).await });
+
+        Ok((
+            ServerHandle {
+                shutdown: Some(shutdown_tx),
+                runtime: Some(runtime),
+                events: Some(events),
+            },
+            Urls { backend, ws },
+        ))
+    }
+}
+
+#[cfg(mobile)]
+impl ServerHandle {
+    /// Boot the in-process backend on a background runtime. On mobile there is
+    /// no loopback socket: the UI reaches the backend over the registered
+    /// custom IPC scheme (see [`crate::mobile`]) and events arrive through the
+    /// Tauri event bridge, so no TCP listener is bound.
+    pub fn start() -> io::Result<(Self, Urls)> {
+        let runtime = Runtime::new()?;
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let (tx, events) = backend::subscribe();
+
+        runtime.spawn(async move { serve_in_process(tx, shutdown_rx).await });
+
+        let backend =
+            std::env::var("P2PCHAT_BACKEND_URL").unwrap_or_else(|_| crate::mobile::backend_url());
+        let ws = std::env::var("P2PCHAT_WS_URL").unwrap_or_else(|_| crate::mobile::ws_url());
+
+        Ok((
+            ServerHandle {
+                shutdown: Some(shutdown_tx),
+                runtime: Some(runtime),
+                events: Some(events),
+            },
+            Urls { backend, ws },
+        ))
+    }
+}
+
+impl Drop for ServerHandle {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(runtime) = self.runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+/// Hold the bound HTTP and WS listeners until the shutdown signal fires, so the
+/// ports stay reserved. No connections are accepted on them and no events are
+/// sent on `_tx`.
+#[cfg(not(mobile))]
+async fn serve(
+    _http: Option<std::net::TcpListener>,
+    _ws: Option<std::net::TcpListener>,
+    _tx: mpsc::UnboundedSender<Event>,
+    shutdown: oneshot::Receiver<()>,
+) {
+    let _ = shutdown.await;
+}
+
+/// Hold the event sender until shutdown (mobile). No events are sent on `_tx`.
+#[cfg(mobile)]
+async fn serve_in_process(
+    _tx: mpsc::UnboundedSender<Event>,
+    shutdown: oneshot::Receiver<()>,
+) {
+    let _ = shutdown.await;
+}